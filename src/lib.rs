@@ -1,3 +1,6 @@
+use std::borrow::Cow;
+use std::fmt;
+
 use nom::IResult;
 use rust_decimal::prelude::ToPrimitive;
 use thiserror::Error;
@@ -31,6 +34,11 @@ pub enum LrcItem<'a> {
     Metadata(LrcMetadata<'a>),
     /// Lyric text and timestamp in milliseconds without offset
     Lyric(&'a str, Vec<i64>),
+    /// Word-timed (Enhanced LRC / A2) lyric: the line-start timestamps in
+    /// milliseconds without offset, plus each `(word_text, start_ms)` segment
+    /// in order, where `word_text` is the text between one inline `<mm:ss.xx>`
+    /// marker (or the line start) and the next
+    WordTimedLyric(Vec<i64>, Vec<(&'a str, i64)>),
 }
 
 #[derive(Debug, Error)]
@@ -43,6 +51,64 @@ pub enum LrcParseError {
     InvalidOffset(usize),
 }
 
+/// Converts a `minute` / `sec` (`ss.xx`) pair, as captured from either a
+/// `[mm:ss.xx]` or `<mm:ss.xx>` marker, into milliseconds.
+fn timestamp_ms(minute: &str, sec: &str, line_num: usize) -> Result<i64, LrcParseError> {
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    let millisec = Decimal::from_str_exact(&sec.replace(':', "."))
+        .map_err(|_| LrcParseError::InvalidTimestamp(line_num))?
+        * dec!(1000);
+    Ok(minute
+        .parse::<i64>()
+        .map_err(|_| LrcParseError::InvalidTimestamp(line_num))?
+        * 60
+        * 1000
+        + millisec
+            .to_i64()
+            .ok_or(LrcParseError::InvalidTimestamp(line_num))?)
+}
+
+/// Scans `text` for inline `<mm:ss.xx>` word markers and splits it into
+/// `(word_text, start_ms)` segments, with the fragment preceding the first
+/// marker (if any) starting at `line_start_ms`. Returns `None` when `text`
+/// carries no inline markers at all, so the caller can fall back to a plain
+/// `LrcItem::Lyric`.
+fn parse_word_segments(
+    text: &str,
+    line_start_ms: i64,
+    line_num: usize,
+) -> Result<Option<Vec<(&str, i64)>>, LrcParseError> {
+    use nom::{bytes::complete::tag, bytes::complete::take_until, sequence::tuple};
+
+    let Some(first_lt) = text.find('<') else {
+        return Ok(None);
+    };
+
+    let mut segments = vec![(&text[..first_lt], line_start_ms)];
+    let mut remaining = &text[first_lt..];
+    while !remaining.is_empty() {
+        let mut marker_parser = tuple((
+            tag("<"),
+            take_until(":"),
+            tag(":"),
+            take_until(">"),
+            tag(">"),
+        ));
+        let parse_result: IResult<&str, (&str, &str, &str, &str, &str)> =
+            marker_parser(remaining);
+        let (after_marker, (_lt, minute, _colon, sec, _gt)) =
+            parse_result.map_err(|_| LrcParseError::InvalidTimestamp(line_num))?;
+        let start_ms = timestamp_ms(minute, sec, line_num)?;
+        let next_lt = after_marker.find('<').unwrap_or(after_marker.len());
+        let (word, after_word) = after_marker.split_at(next_lt);
+        segments.push((word, start_ms));
+        remaining = after_word;
+    }
+    Ok(Some(segments))
+}
+
 pub fn parse_single(line: &str, line_num: usize) -> Result<Option<LrcItem<'_>>, LrcParseError> {
     use nom::{
         bytes::complete::{tag, take_until},
@@ -50,9 +116,6 @@ pub fn parse_single(line: &str, line_num: usize) -> Result<Option<LrcItem<'_>>,
         sequence::tuple,
     };
 
-    use rust_decimal::Decimal;
-    use rust_decimal_macros::dec;
-
     let mut tag_parser = many1(tuple((
         tag("["),
         take_until(":"),
@@ -89,20 +152,12 @@ pub fn parse_single(line: &str, line_num: usize) -> Result<Option<LrcItem<'_>>,
             _minute if _minute.parse::<i64>().is_ok() => {
                 let mut timestamps = Vec::with_capacity(tags.len());
                 for (_left_sq, minute, _semicon, sec, _right_sq) in tags {
-                    let millisec = Decimal::from_str_exact(&sec.replace(':', "."))
-                        .map_err(|_| LrcParseError::InvalidTimestamp(line_num))?
-                        * dec!(1000);
-                    let timestamp = minute
-                        .parse::<i64>()
-                        .map_err(|_| LrcParseError::InvalidTimestamp(line_num))?
-                        * 60
-                        * 1000
-                        + millisec
-                            .to_i64()
-                            .ok_or(LrcParseError::InvalidTimestamp(line_num))?;
-                    timestamps.push(timestamp);
+                    timestamps.push(timestamp_ms(minute, sec, line_num)?);
+                }
+                match parse_word_segments(text, timestamps[0], line_num)? {
+                    Some(segments) => LrcItem::WordTimedLyric(timestamps, segments),
+                    None => LrcItem::Lyric(text, timestamps),
                 }
-                LrcItem::Lyric(text, timestamps)
             }
             _ => return Ok(None), // ignores unrecognised tags
         },
@@ -112,13 +167,737 @@ pub fn parse_single(line: &str, line_num: usize) -> Result<Option<LrcItem<'_>>,
 pub fn parse<'a>(
     lyric_lines: impl Iterator<Item = &'a str>,
 ) -> Result<Vec<LrcItem<'a>>, LrcParseError> {
-    let mut lrc_tags = Vec::new();
+    let (items, mut warnings) = parse_with_options(lyric_lines, &ParseOptions::default());
+    if let Some((_line_num, err)) = warnings.pop() {
+        return Err(err);
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod word_timed_lyric_tests {
+    use super::*;
+
+    #[test]
+    fn line_without_inline_markers_stays_plain() {
+        let item = parse_single("[00:17.29]I am a singer", 0).unwrap().unwrap();
+        assert_eq!(item, LrcItem::Lyric("I am a singer", vec![17_290]));
+    }
+
+    #[test]
+    fn line_with_inline_markers_becomes_word_timed() {
+        let item = parse_single(
+            "[00:17.29]<00:17.29>I <00:17.80>am <00:18.10>a singer",
+            0,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            item,
+            LrcItem::WordTimedLyric(
+                vec![17_290],
+                vec![("", 17_290), ("I ", 17_290), ("am ", 17_800), ("a singer", 18_100)],
+            )
+        );
+    }
+
+    #[test]
+    fn malformed_inline_marker_is_invalid_timestamp() {
+        let err = parse_single("[00:17.29]<00:17.29>I <oops>am a singer", 3).unwrap_err();
+        assert!(matches!(err, LrcParseError::InvalidTimestamp(3)));
+    }
+}
+
+/// A `(line_num, error)` pair recording a line skipped during a lenient
+/// [`parse_with_options`] call.
+pub type Warning = (usize, LrcParseError);
+
+/// Builder controlling how [`parse_with_options`] handles metadata tags,
+/// malformed lines, and lyric whitespace. Unknown tags are always dropped.
+/// Construct with [`ParseOptions::new`] (equivalent to
+/// [`ParseOptions::default`]) and chain the setters.
+#[derive(Debug, Clone)]
+pub struct ParseOptions {
+    read_metadata: bool,
+    lenient: bool,
+    trim_lyric_whitespace: bool,
+}
+
+impl Default for ParseOptions {
+    /// Matches the behavior of the strict [`parse`]: metadata is kept,
+    /// malformed lines abort the parse, and lyric text whitespace is
+    /// preserved exactly as written.
+    fn default() -> Self {
+        Self {
+            read_metadata: true,
+            lenient: false,
+            trim_lyric_whitespace: false,
+        }
+    }
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// When `false`, metadata tags (`[ar: ...]`, `[offset: ...]`, ...) are
+    /// dropped, keeping only timed lyrics.
+    pub fn read_metadata(mut self, read_metadata: bool) -> Self {
+        self.read_metadata = read_metadata;
+        self
+    }
+
+    /// When `true`, a line that would otherwise raise
+    /// [`LrcParseError::NoTagInNonEmptyLine`] or
+    /// [`LrcParseError::InvalidTimestamp`] (or any other parse error) is
+    /// skipped and recorded as a [`Warning`] instead of aborting the parse.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// When `true`, trims surrounding whitespace from lyric text (and word
+    /// segments) instead of preserving it exactly as written.
+    pub fn trim_lyric_whitespace(mut self, trim_lyric_whitespace: bool) -> Self {
+        self.trim_lyric_whitespace = trim_lyric_whitespace;
+        self
+    }
+}
+
+fn apply_lyric_whitespace_option<'a>(item: LrcItem<'a>, options: &ParseOptions) -> LrcItem<'a> {
+    if !options.trim_lyric_whitespace {
+        return item;
+    }
+    match item {
+        LrcItem::Lyric(text, timestamps) => LrcItem::Lyric(text.trim(), timestamps),
+        LrcItem::WordTimedLyric(timestamps, segments) => {
+            // Only trim the outer edges of the whole line: a segment's
+            // trailing space is the separator before the next word, so
+            // trimming every segment independently would mangle the
+            // rejoined text (e.g. "I " + "am " -> "Iam" instead of "I am").
+            // Leading/trailing segments may be empty (e.g. the fragment
+            // before the first marker), so keep trimming inward until a
+            // segment with text remains.
+            let mut segments = segments;
+            for (word, _) in segments.iter_mut() {
+                *word = word.trim_start();
+                if !word.is_empty() {
+                    break;
+                }
+            }
+            for (word, _) in segments.iter_mut().rev() {
+                *word = word.trim_end();
+                if !word.is_empty() {
+                    break;
+                }
+            }
+            LrcItem::WordTimedLyric(timestamps, segments)
+        }
+        other => other,
+    }
+}
+
+/// Parses `lyric_lines` under `options`, never aborting outright: malformed
+/// or (when `read_metadata` is off) unwanted lines are simply skipped, with
+/// skips from parse errors recorded as `(line_num, error)` warnings. In
+/// non-lenient mode, the first parse error still stops further lines from
+/// being processed, matching the strict [`parse`] behavior; set
+/// [`ParseOptions::lenient`] to keep going and collect every warning
+/// instead.
+pub fn parse_with_options<'a>(
+    lyric_lines: impl Iterator<Item = &'a str>,
+    options: &ParseOptions,
+) -> (Vec<LrcItem<'a>>, Vec<Warning>) {
+    let mut items = Vec::new();
+    let mut warnings = Vec::new();
 
     for (line_num, line) in lyric_lines.enumerate() {
-        if let Some(tag) = parse_single(line, line_num)? {
-            lrc_tags.push(tag);
+        match parse_single(line, line_num) {
+            Ok(Some(LrcItem::Metadata(_))) if !options.read_metadata => {}
+            Ok(Some(item)) => items.push(apply_lyric_whitespace_option(item, options)),
+            Ok(None) => {}
+            Err(err) => {
+                warnings.push((line_num, err));
+                if !options.lenient {
+                    break;
+                }
+            }
         }
     }
 
-    Ok(lrc_tags)
+    (items, warnings)
+}
+
+#[cfg(test)]
+mod parse_options_tests {
+    use super::*;
+
+    #[test]
+    fn read_metadata_false_drops_metadata_tags() {
+        let lines = ["[ar:Artist]", "[00:17.29]I am a singer"];
+        let options = ParseOptions::new().read_metadata(false);
+        let (items, warnings) = parse_with_options(lines.into_iter(), &options);
+        assert!(warnings.is_empty());
+        assert_eq!(items, vec![LrcItem::Lyric("I am a singer", vec![17_290])]);
+    }
+
+    #[test]
+    fn strict_mode_stops_at_first_error() {
+        let lines = ["[00:17.29]I am a singer", "no tag here", "[00:18.00]next line"];
+        let options = ParseOptions::new();
+        let (items, warnings) = parse_with_options(lines.into_iter(), &options);
+        assert_eq!(items, vec![LrcItem::Lyric("I am a singer", vec![17_290])]);
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(warnings[0], (1, LrcParseError::NoTagInNonEmptyLine(1))));
+    }
+
+    #[test]
+    fn lenient_mode_collects_every_warning_and_keeps_going() {
+        let lines = ["[00:17.29]I am a singer", "no tag here", "[00:18.00]next line"];
+        let options = ParseOptions::new().lenient(true);
+        let (items, warnings) = parse_with_options(lines.into_iter(), &options);
+        assert_eq!(
+            items,
+            vec![
+                LrcItem::Lyric("I am a singer", vec![17_290]),
+                LrcItem::Lyric("next line", vec![18_000]),
+            ]
+        );
+        assert_eq!(warnings.len(), 1);
+        assert!(matches!(warnings[0], (1, LrcParseError::NoTagInNonEmptyLine(1))));
+    }
+
+    #[test]
+    fn trim_lyric_whitespace_trims_only_outer_edges_of_word_timed_line() {
+        let lines = ["[00:17.29]<00:17.29>I <00:17.80>am <00:18.10>a singer "];
+        let options = ParseOptions::new().trim_lyric_whitespace(true);
+        let (items, _warnings) = parse_with_options(lines.into_iter(), &options);
+        let LrcItem::WordTimedLyric(_, segments) = &items[0] else {
+            panic!("expected a word-timed lyric");
+        };
+        let rejoined: String = segments.iter().map(|(word, _)| *word).collect();
+        assert_eq!(rejoined, "I am a singer");
+    }
+
+    #[test]
+    fn trim_lyric_whitespace_trims_plain_lyric_text() {
+        let lines = ["[00:17.29] I am a singer "];
+        let options = ParseOptions::new().trim_lyric_whitespace(true);
+        let (items, _warnings) = parse_with_options(lines.into_iter(), &options);
+        assert_eq!(items, vec![LrcItem::Lyric("I am a singer", vec![17_290])]);
+    }
+}
+
+/// A searchable timeline built from parsed [`LrcItem`]s, letting a player
+/// look up the line that should be displayed at a given playback position
+/// without re-scanning on every tick.
+pub struct Lyrics<'a> {
+    /// `(time_ms, text)` pairs, offset-adjusted and sorted ascending by time.
+    lines: Vec<(i64, Cow<'a, str>)>,
+}
+
+impl<'a> Lyrics<'a> {
+    /// Flattens every [`LrcItem::Lyric`] into one `(time_ms, text)` pair per
+    /// timestamp it carries (a single line may be stamped at several points
+    /// in the song), applies any [`LrcMetadata::Offset`] found among `items`,
+    /// and sorts the result by time. [`LrcItem::WordTimedLyric`] lines are
+    /// folded in too, using their line-start timestamps and their word
+    /// segments rejoined (markers stripped) as the displayable text.
+    pub fn from_items(items: Vec<LrcItem<'a>>) -> Self {
+        let offset = items
+            .iter()
+            .find_map(|item| match item {
+                LrcItem::Metadata(LrcMetadata::Offset(offset)) => Some(*offset),
+                _ => None,
+            })
+            .unwrap_or(0);
+
+        let mut lines: Vec<(i64, Cow<'a, str>)> = items
+            .into_iter()
+            .filter_map(|item| match item {
+                LrcItem::Lyric(text, timestamps) => Some((Cow::Borrowed(text), timestamps)),
+                LrcItem::WordTimedLyric(timestamps, segments) => {
+                    let text: String = segments.into_iter().map(|(word, _)| word).collect();
+                    Some((Cow::Owned(text), timestamps))
+                }
+                _ => None,
+            })
+            .flat_map(|(text, timestamps)| {
+                timestamps
+                    .into_iter()
+                    .map(move |time_ms| (time_ms.saturating_add(offset).max(0), text.clone()))
+            })
+            .collect();
+
+        lines.sort_by_key(|(time_ms, _)| *time_ms);
+        Self { lines }
+    }
+
+    /// Returns the index of the last line whose timestamp is `<= position_ms`,
+    /// or `None` if `position_ms` is before the first line.
+    pub fn index_at(&self, position_ms: i64) -> Option<usize> {
+        let idx = self
+            .lines
+            .partition_point(|(time_ms, _)| *time_ms <= position_ms);
+        idx.checked_sub(1)
+    }
+
+    /// Returns the text of the line that should be displayed at `position_ms`.
+    pub fn line_at(&self, position_ms: i64) -> Option<&str> {
+        self.index_at(position_ms).map(|idx| self.lines[idx].1.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod lyrics_tests {
+    use super::*;
+
+    #[test]
+    fn before_first_line_returns_none() {
+        let lyrics = Lyrics::from_items(vec![LrcItem::Lyric("first", vec![1_000])]);
+        assert_eq!(lyrics.line_at(500), None);
+        assert_eq!(lyrics.index_at(500), None);
+    }
+
+    #[test]
+    fn exact_boundary_and_in_between_positions() {
+        let lyrics = Lyrics::from_items(vec![
+            LrcItem::Lyric("first", vec![1_000]),
+            LrcItem::Lyric("second", vec![2_000]),
+        ]);
+        assert_eq!(lyrics.line_at(1_000), Some("first"));
+        assert_eq!(lyrics.line_at(1_500), Some("first"));
+        assert_eq!(lyrics.line_at(2_000), Some("second"));
+        assert_eq!(lyrics.line_at(5_000), Some("second"));
+    }
+
+    #[test]
+    fn multiple_timestamps_per_line_each_become_an_entry() {
+        let lyrics = Lyrics::from_items(vec![LrcItem::Lyric("chorus", vec![1_000, 5_000])]);
+        assert_eq!(lyrics.line_at(1_000), Some("chorus"));
+        assert_eq!(lyrics.line_at(3_000), Some("chorus"));
+        assert_eq!(lyrics.line_at(5_000), Some("chorus"));
+        assert_eq!(lyrics.index_at(5_000), Some(1));
+    }
+
+    #[test]
+    fn negative_offset_is_applied_and_clamped_at_zero() {
+        let lyrics = Lyrics::from_items(vec![
+            LrcItem::Metadata(LrcMetadata::Offset(-2_000)),
+            LrcItem::Lyric("first", vec![1_000]),
+            LrcItem::Lyric("second", vec![3_000]),
+        ]);
+        // 1_000 - 2_000 would go negative; it must clamp to 0, not corrupt sort order.
+        assert_eq!(lyrics.line_at(0), Some("first"));
+        assert_eq!(lyrics.line_at(900), Some("first"));
+        assert_eq!(lyrics.line_at(1_000), Some("second"));
+    }
+
+    #[test]
+    fn word_timed_lines_are_folded_into_the_timeline() {
+        let items = parse(
+            ["[00:01.00]<00:01.00>I <00:01.50>am <00:02.00>a singer"].into_iter(),
+        )
+        .unwrap();
+        let lyrics = Lyrics::from_items(items);
+        assert_eq!(lyrics.line_at(1_200), Some("I am a singer"));
+    }
+}
+
+/// A parsed LRC document. Wraps the `Vec<LrcItem>` produced by [`parse`] so
+/// it can implement [`fmt::Display`], rendering itself back to canonical LRC
+/// text via [`write_lrc`].
+pub struct LrcDocument<'a>(pub Vec<LrcItem<'a>>);
+
+impl fmt::Display for LrcDocument<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_lrc(&self.0, f)
+    }
+}
+
+/// Formats milliseconds as the zero-padded `mm:ss.xx` timestamp form used
+/// inside `[mm:ss.xx]` and `<mm:ss.xx>` markers, reversing the math in
+/// [`timestamp_ms`].
+fn format_timestamp(ms: i64) -> String {
+    let minutes = ms / 60_000;
+    let secs_in_minute = ms % 60_000;
+    let seconds = secs_in_minute / 1000;
+    let centiseconds = (secs_in_minute % 1000) / 10;
+    format!("{minutes:02}:{seconds:02}.{centiseconds:02}")
+}
+
+fn write_metadata(metadata: &LrcMetadata<'_>, out: &mut impl fmt::Write) -> fmt::Result {
+    match metadata {
+        LrcMetadata::Artist(v) => writeln!(out, "[ar:{v}]"),
+        LrcMetadata::Album(v) => writeln!(out, "[al:{v}]"),
+        LrcMetadata::Title(v) => writeln!(out, "[ti:{v}]"),
+        LrcMetadata::Lyricist(v) => writeln!(out, "[au:{v}]"),
+        LrcMetadata::Author(v) => writeln!(out, "[by:{v}]"),
+        LrcMetadata::Length(v) => writeln!(out, "[length:{v}]"),
+        LrcMetadata::Offset(v) => writeln!(out, "[offset:{v}]"),
+        LrcMetadata::Application(v) => writeln!(out, "[re:{v}]"),
+        LrcMetadata::AppVersion(v) => writeln!(out, "[ve:{v}]"),
+        LrcMetadata::Comment(v) => writeln!(out, "[#:{v}]"),
+    }
+}
+
+fn write_item(item: &LrcItem<'_>, out: &mut impl fmt::Write) -> fmt::Result {
+    match item {
+        LrcItem::Metadata(metadata) => write_metadata(metadata, out),
+        LrcItem::Lyric(text, timestamps) => {
+            for ts in timestamps {
+                write!(out, "[{}]", format_timestamp(*ts))?;
+            }
+            writeln!(out, "{text}")
+        }
+        LrcItem::WordTimedLyric(timestamps, segments) => {
+            for ts in timestamps {
+                write!(out, "[{}]", format_timestamp(*ts))?;
+            }
+            for (i, (word, start_ms)) in segments.iter().enumerate() {
+                if i == 0 {
+                    write!(out, "{word}")?;
+                } else {
+                    write!(out, "<{}>{word}", format_timestamp(*start_ms))?;
+                }
+            }
+            writeln!(out)
+        }
+    }
+}
+
+/// Renders `items` back to canonical LRC text, reversing the millisecond →
+/// timestamp math used by [`parse_single`]. A line carrying multiple
+/// timestamps emits all of them concatenated before the text, matching the
+/// repeated-tag form the parser accepts, so that parse → serialize → parse
+/// round-trips are stable.
+pub fn write_lrc(items: &[LrcItem<'_>], out: &mut impl fmt::Write) -> fmt::Result {
+    for item in items {
+        write_item(item, out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod write_lrc_tests {
+    use super::*;
+
+    #[test]
+    fn comment_round_trips() {
+        let items = vec![LrcItem::Metadata(LrcMetadata::Comment("hello world"))];
+        let mut rendered = String::new();
+        write_lrc(&items, &mut rendered).unwrap();
+        assert_eq!(parse(rendered.lines()).unwrap(), items);
+    }
+
+    #[test]
+    fn metadata_round_trips() {
+        let items = vec![
+            LrcItem::Metadata(LrcMetadata::Artist("Artist")),
+            LrcItem::Metadata(LrcMetadata::Title("Title")),
+            LrcItem::Metadata(LrcMetadata::Offset(-250)),
+        ];
+        let mut rendered = String::new();
+        write_lrc(&items, &mut rendered).unwrap();
+        assert_eq!(parse(rendered.lines()).unwrap(), items);
+    }
+
+    #[test]
+    fn lyric_with_multiple_timestamps_round_trips() {
+        let items = vec![LrcItem::Lyric("I am a singer", vec![17_290, 77_290])];
+        let mut rendered = String::new();
+        write_lrc(&items, &mut rendered).unwrap();
+        assert_eq!(parse(rendered.lines()).unwrap(), items);
+    }
+
+    #[test]
+    fn word_timed_lyric_round_trips() {
+        let items = vec![LrcItem::WordTimedLyric(
+            vec![17_290],
+            vec![
+                ("", 17_290),
+                ("I ", 17_290),
+                ("am ", 17_800),
+                ("a singer", 18_100),
+            ],
+        )];
+        let mut rendered = String::new();
+        write_lrc(&items, &mut rendered).unwrap();
+        assert_eq!(parse(rendered.lines()).unwrap(), items);
+    }
+}
+
+/// Owned mirror of [`LrcMetadata`], for editors that outlive the parsed input.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum OwnedLrcMetadata {
+    /// Artist of the song
+    Artist(String),
+    /// Album this song belongs to
+    Album(String),
+    /// Title of the song
+    Title(String),
+    /// Lyricist wrote this songtext
+    Lyricist(String),
+    /// Author of this LRC
+    Author(String),
+    /// Length of the song
+    Length(String),
+    /// Offset in milliseconds
+    Offset(i64),
+    /// The player or editor that created the LRC file
+    Application(String),
+    /// version of the app above
+    AppVersion(String),
+    /// Comments
+    Comment(String),
+}
+
+impl LrcMetadata<'_> {
+    /// Clones the borrowed text fields into an owned copy.
+    pub fn to_owned(&self) -> OwnedLrcMetadata {
+        match self {
+            LrcMetadata::Artist(v) => OwnedLrcMetadata::Artist(v.to_string()),
+            LrcMetadata::Album(v) => OwnedLrcMetadata::Album(v.to_string()),
+            LrcMetadata::Title(v) => OwnedLrcMetadata::Title(v.to_string()),
+            LrcMetadata::Lyricist(v) => OwnedLrcMetadata::Lyricist(v.to_string()),
+            LrcMetadata::Author(v) => OwnedLrcMetadata::Author(v.to_string()),
+            LrcMetadata::Length(v) => OwnedLrcMetadata::Length(v.to_string()),
+            LrcMetadata::Offset(v) => OwnedLrcMetadata::Offset(*v),
+            LrcMetadata::Application(v) => OwnedLrcMetadata::Application(v.to_string()),
+            LrcMetadata::AppVersion(v) => OwnedLrcMetadata::AppVersion(v.to_string()),
+            LrcMetadata::Comment(v) => OwnedLrcMetadata::Comment(v.to_string()),
+        }
+    }
+}
+
+/// Owned mirror of [`LrcItem`], for editors that mutate lyrics and re-time
+/// lines after the original borrowed input has gone away.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum OwnedLrcItem {
+    Metadata(OwnedLrcMetadata),
+    /// Lyric text and timestamp in milliseconds without offset
+    Lyric(String, Vec<i64>),
+    /// Word-timed (Enhanced LRC / A2) lyric, see [`LrcItem::WordTimedLyric`]
+    WordTimedLyric(Vec<i64>, Vec<(String, i64)>),
+}
+
+impl LrcItem<'_> {
+    /// Clones the borrowed text fields into an owned copy.
+    pub fn to_owned(&self) -> OwnedLrcItem {
+        match self {
+            LrcItem::Metadata(metadata) => OwnedLrcItem::Metadata(metadata.to_owned()),
+            LrcItem::Lyric(text, timestamps) => {
+                OwnedLrcItem::Lyric(text.to_string(), timestamps.clone())
+            }
+            LrcItem::WordTimedLyric(timestamps, segments) => OwnedLrcItem::WordTimedLyric(
+                timestamps.clone(),
+                segments
+                    .iter()
+                    .map(|(word, start_ms)| (word.to_string(), *start_ms))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// An editing-oriented lyric document: an ordered, owned list of timed
+/// lines that supports the operations an LRC editor needs — inserting a
+/// line at a timestamp, shifting every timestamp by a delta to re-sync the
+/// whole document, retiming a single line, and removing a line. Unlike
+/// [`Lyrics`], which is a read-only lookup timeline, `LyricsDoc` is meant to
+/// be mutated interactively and then serialized back out (e.g. via
+/// [`write_lrc`] over [`LyricsDoc::to_items`]).
+#[derive(Debug, Default, Clone)]
+pub struct LyricsDoc {
+    lines: Vec<(i64, String)>,
+}
+
+impl LyricsDoc {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a document from parsed items, flattening every
+    /// [`LrcItem::Lyric`] into one `(time_ms, text)` line per timestamp it
+    /// carries and sorting the result by time. [`LrcItem::WordTimedLyric`]
+    /// lines are folded in too, using their line-start timestamps and their
+    /// word segments rejoined (markers stripped) as the line text. Metadata
+    /// is not represented in the editable line list.
+    pub fn from_items(items: &[LrcItem<'_>]) -> Self {
+        let mut lines: Vec<(i64, String)> = items
+            .iter()
+            .filter_map(|item| match item {
+                LrcItem::Lyric(text, timestamps) => Some((text.to_string(), timestamps.clone())),
+                LrcItem::WordTimedLyric(timestamps, segments) => {
+                    let text: String = segments.iter().map(|(word, _)| *word).collect();
+                    Some((text, timestamps.clone()))
+                }
+                _ => None,
+            })
+            .flat_map(|(text, timestamps)| {
+                timestamps.into_iter().map(move |time_ms| (time_ms, text.clone()))
+            })
+            .collect();
+        lines.sort_by_key(|(time_ms, _)| *time_ms);
+        Self { lines }
+    }
+
+    /// Inserts a new line at `time_ms`, keeping the document sorted by time.
+    pub fn insert_line(&mut self, time_ms: i64, text: impl Into<String>) {
+        let idx = self.lines.partition_point(|(t, _)| *t <= time_ms);
+        self.lines.insert(idx, (time_ms, text.into()));
+    }
+
+    /// Shifts every line's timestamp by `delta_ms` (negative to move
+    /// earlier), clamping at zero so a large negative shift can't corrupt
+    /// sort order, then re-sorts.
+    pub fn shift(&mut self, delta_ms: i64) {
+        for (time_ms, _) in &mut self.lines {
+            *time_ms = time_ms.saturating_add(delta_ms).max(0);
+        }
+        self.lines.sort_by_key(|(time_ms, _)| *time_ms);
+    }
+
+    /// Moves the line at `index` to `new_time_ms`, keeping the document
+    /// sorted by time. Does nothing if `index` is out of bounds.
+    pub fn retime(&mut self, index: usize, new_time_ms: i64) {
+        if index >= self.lines.len() {
+            return;
+        }
+        let (_, text) = self.lines.remove(index);
+        let new_idx = self.lines.partition_point(|(t, _)| *t <= new_time_ms);
+        self.lines.insert(new_idx, (new_time_ms, text));
+    }
+
+    /// Removes and returns the line at `index`, or `None` if out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<(i64, String)> {
+        if index >= self.lines.len() {
+            return None;
+        }
+        Some(self.lines.remove(index))
+    }
+
+    /// The document's lines in time order, as `(time_ms, text)` pairs.
+    pub fn lines(&self) -> &[(i64, String)] {
+        &self.lines
+    }
+
+    /// Borrows the document as [`LrcItem`]s suitable for [`write_lrc`].
+    pub fn to_items(&self) -> Vec<LrcItem<'_>> {
+        self.lines
+            .iter()
+            .map(|(time_ms, text)| LrcItem::Lyric(text.as_str(), vec![*time_ms]))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod owned_model_tests {
+    use super::*;
+
+    #[test]
+    fn lrc_metadata_to_owned_converts_every_variant() {
+        assert_eq!(
+            LrcMetadata::Artist("Artist").to_owned(),
+            OwnedLrcMetadata::Artist("Artist".to_string())
+        );
+        assert_eq!(
+            LrcMetadata::Offset(-250).to_owned(),
+            OwnedLrcMetadata::Offset(-250)
+        );
+    }
+
+    #[test]
+    fn lrc_item_to_owned_converts_lyric_and_word_timed_variants() {
+        assert_eq!(
+            LrcItem::Lyric("line", vec![1_000]).to_owned(),
+            OwnedLrcItem::Lyric("line".to_string(), vec![1_000])
+        );
+        assert_eq!(
+            LrcItem::WordTimedLyric(vec![1_000], vec![("word", 1_000)]).to_owned(),
+            OwnedLrcItem::WordTimedLyric(vec![1_000], vec![("word".to_string(), 1_000)])
+        );
+    }
+
+    #[test]
+    fn from_items_flattens_lyric_and_word_timed_lines_sorted_by_time() {
+        let items = vec![
+            LrcItem::Lyric("second", vec![2_000]),
+            LrcItem::WordTimedLyric(vec![1_000], vec![("fir", 1_000), ("st", 1_500)]),
+            LrcItem::Metadata(LrcMetadata::Artist("Artist")),
+        ];
+        let doc = LyricsDoc::from_items(&items);
+        assert_eq!(
+            doc.lines(),
+            &[(1_000, "first".to_string()), (2_000, "second".to_string())]
+        );
+    }
+
+    #[test]
+    fn insert_line_keeps_document_sorted() {
+        let mut doc = LyricsDoc::new();
+        doc.insert_line(2_000, "second");
+        doc.insert_line(1_000, "first");
+        doc.insert_line(3_000, "third");
+        assert_eq!(
+            doc.lines(),
+            &[
+                (1_000, "first".to_string()),
+                (2_000, "second".to_string()),
+                (3_000, "third".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn shift_moves_every_timestamp_and_clamps_at_zero() {
+        let mut doc = LyricsDoc::new();
+        doc.insert_line(1_000, "first");
+        doc.insert_line(3_000, "second");
+        doc.shift(-2_000);
+        assert_eq!(
+            doc.lines(),
+            &[(0, "first".to_string()), (1_000, "second".to_string())]
+        );
+    }
+
+    #[test]
+    fn retime_reorders_the_line_it_moves() {
+        let mut doc = LyricsDoc::new();
+        doc.insert_line(1_000, "first");
+        doc.insert_line(2_000, "second");
+        doc.retime(0, 3_000);
+        assert_eq!(
+            doc.lines(),
+            &[(2_000, "second".to_string()), (3_000, "first".to_string())]
+        );
+    }
+
+    #[test]
+    fn remove_drops_the_line_and_returns_it() {
+        let mut doc = LyricsDoc::new();
+        doc.insert_line(1_000, "first");
+        doc.insert_line(2_000, "second");
+        assert_eq!(doc.remove(0), Some((1_000, "first".to_string())));
+        assert_eq!(doc.lines(), &[(2_000, "second".to_string())]);
+        assert_eq!(doc.remove(5), None);
+    }
+
+    #[test]
+    fn to_items_round_trips_through_write_lrc() {
+        let mut doc = LyricsDoc::new();
+        doc.insert_line(1_000, "first");
+        doc.insert_line(2_000, "second");
+        let items = doc.to_items();
+        let mut rendered = String::new();
+        write_lrc(&items, &mut rendered).unwrap();
+        assert_eq!(
+            parse(rendered.lines()).unwrap(),
+            vec![
+                LrcItem::Lyric("first", vec![1_000]),
+                LrcItem::Lyric("second", vec![2_000]),
+            ]
+        );
+    }
 }